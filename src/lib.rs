@@ -1,8 +1,68 @@
 //! This module provides data structures and functions for interacting with canteens
 //! and their meals using the OpenMensa API.
 
-use reqwest::Error;
-use serde::Deserialize;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+
+/// The error type returned by every fallible function in this crate.
+///
+/// Most operations talk to the network, but the caching layer also touches the
+/// filesystem and (de)serializes JSON, so the variants cover all three.
+#[derive(Debug)]
+pub enum Error {
+    /// An error originating from the HTTP client.
+    Request(reqwest::Error),
+    /// An error (de)serializing a JSON body or cache entry.
+    Serde(serde_json::Error),
+    /// An error reading or writing the on-disk cache.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "request error: {err}"),
+            Error::Serde(err) => write!(f, "serialization error: {err}"),
+            Error::Io(err) => write!(f, "cache i/o error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(err) => Some(err),
+            Error::Serde(err) => Some(err),
+            Error::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Serde(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
 
 /// Represents a canteen with its details.
 #[allow(dead_code)]
@@ -20,6 +80,31 @@ pub struct Canteen {
     pub coordinates: Option<[f64; 2]>,
 }
 
+impl Canteen {
+    /// Computes the great-circle distance in kilometres from this canteen to the
+    /// given coordinates using the haversine formula (Earth radius 6371 km).
+    ///
+    /// # Arguments
+    ///
+    /// * `lat` - The latitude of the reference point in degrees.
+    /// * `lon` - The longitude of the reference point in degrees.
+    ///
+    /// # Returns
+    ///
+    /// The distance in kilometres, or `None` if the canteen has no coordinates.
+    pub fn distance_to(&self, lat: f64, lon: f64) -> Option<f64> {
+        let [c_lat, c_lon] = self.coordinates?;
+        const R: f64 = 6371.0;
+        let d_phi = (lat - c_lat).to_radians();
+        let d_lambda = (lon - c_lon).to_radians();
+        let phi1 = c_lat.to_radians();
+        let phi2 = lat.to_radians();
+        let a = (d_phi / 2.0).sin().powi(2)
+            + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+        Some(2.0 * R * a.sqrt().atan2((1.0 - a).sqrt()))
+    }
+}
+
 /// Represents the price structure for different user groups.
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
@@ -34,6 +119,39 @@ pub struct Prices {
     pub others: Option<f64>,
 }
 
+/// Identifies one of the user groups a [`Prices`] entry covers.
+#[derive(Debug, Clone, Copy)]
+pub enum PriceGroup {
+    /// The student price group.
+    Students,
+    /// The employee price group.
+    Employees,
+    /// The pupil price group.
+    Pupils,
+    /// The "others" price group.
+    Others,
+}
+
+impl Prices {
+    /// Returns the price for the given user group, if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The user group whose price to look up.
+    ///
+    /// # Returns
+    ///
+    /// The price for that group, or `None` if it is not listed.
+    pub fn for_group(&self, group: PriceGroup) -> Option<f64> {
+        match group {
+            PriceGroup::Students => self.students,
+            PriceGroup::Employees => self.employees,
+            PriceGroup::Pupils => self.pupils,
+            PriceGroup::Others => self.others,
+        }
+    }
+}
+
 /// Represents a meal with its details.
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
@@ -48,20 +166,352 @@ pub struct Meal {
     pub prices: Prices,
     /// Additional notes about the meal.
     pub notes: Vec<String>,
+    /// The single price selected for the requested [`PriceGroup`], if any.
+    ///
+    /// Populated by [`MealRequest::build`]; not part of the API response.
+    #[serde(skip)]
+    pub selected_price: Option<f64>,
+}
+
+/// A value that is either not yet present or has already been fetched.
+///
+/// The caching layer uses this to distinguish a cache miss (`None`) from a fresh
+/// enough cache hit (`Fetched`), mirroring the lazy-retrieval pattern the rest of
+/// the crate follows.
+enum Fetchable<T> {
+    /// No usable value is available; the caller must fetch it.
+    None,
+    /// A value that was retrieved from the cache.
+    Fetched(T),
+}
+
+/// An on-disk cache entry: the stored JSON body and the time it was fetched.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// Seconds since the Unix epoch when the body was fetched.
+    fetched_at: u64,
+    /// The cached JSON body.
+    body: serde_json::Value,
+}
+
+/// Returns the directory used to store cached API responses.
+fn cache_dir() -> PathBuf {
+    std::env::temp_dir().join("mensa-cli-backend-cache")
+}
+
+/// Returns the cache file path for the given cache key (typically a request URL).
+fn cache_path(key: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    cache_dir().join(format!("{:016x}.json", hasher.finish()))
+}
+
+/// Returns the number of seconds since the Unix epoch.
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Reads a cached body for `key` if one exists and is younger than `ttl`.
+///
+/// Any I/O or parse failure is treated as a cache miss rather than an error, so
+/// the caller simply falls through to a live request.
+fn read_cache(key: &str, ttl: Duration) -> Fetchable<serde_json::Value> {
+    let Ok(raw) = fs::read_to_string(cache_path(key)) else {
+        return Fetchable::None;
+    };
+    let Ok(entry) = serde_json::from_str::<CacheEntry>(&raw) else {
+        return Fetchable::None;
+    };
+    if now_secs().saturating_sub(entry.fetched_at) <= ttl.as_secs() {
+        Fetchable::Fetched(entry.body)
+    } else {
+        Fetchable::None
+    }
+}
+
+/// Writes `body` to the cache under `key`, stamped with the current time.
+///
+/// Cache writes are best-effort: a failure to persist is silently ignored so a
+/// broken cache never turns a successful fetch into an error.
+fn write_cache(key: &str, body: &serde_json::Value) {
+    let entry = CacheEntry {
+        fetched_at: now_secs(),
+        body: body.clone(),
+    };
+    let _ = fs::create_dir_all(cache_dir());
+    if let Ok(serialized) = serde_json::to_string(&entry) {
+        let _ = fs::write(cache_path(key), serialized);
+    }
+}
+
+/// Clears the on-disk response cache, removing every stored entry.
+///
+/// # Returns
+///
+/// `Ok(())` once the cache directory has been removed, or an I/O error.
+pub fn clear_cache() -> Result<(), Error> {
+    let dir = cache_dir();
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+/// Represents a single day in a canteen's schedule.
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+pub struct Day {
+    /// The date of the day in YYYY-MM-DD format.
+    pub date: String,
+    /// Whether the canteen is closed on this day.
+    pub closed: bool,
+}
+
+/// The maximum number of retries attempted for a transient failure.
+const MAX_RETRIES: u32 = 4;
+/// The initial backoff delay, doubled on each subsequent retry.
+const BASE_BACKOFF: Duration = Duration::from_millis(200);
+/// The ceiling on the backoff delay between retries.
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+/// The default client-side rate limit in requests per second.
+const DEFAULT_RATE_LIMIT: f64 = 5.0;
+
+/// A simple token bucket used to throttle outgoing requests.
+struct TokenBucket {
+    /// The number of tokens currently available.
+    tokens: f64,
+    /// The maximum number of tokens the bucket can hold.
+    capacity: f64,
+    /// The refill rate in tokens (requests) per second.
+    rate: f64,
+    /// The last time the bucket was refilled.
+    last_refill: SystemTime,
+}
+
+/// The process-wide rate limiter shared by every request.
+static RATE_LIMITER: OnceLock<Mutex<TokenBucket>> = OnceLock::new();
+
+/// Returns the shared rate limiter, initializing it on first use.
+fn limiter() -> &'static Mutex<TokenBucket> {
+    RATE_LIMITER.get_or_init(|| {
+        Mutex::new(TokenBucket {
+            tokens: DEFAULT_RATE_LIMIT,
+            capacity: DEFAULT_RATE_LIMIT,
+            rate: DEFAULT_RATE_LIMIT,
+            last_refill: SystemTime::now(),
+        })
+    })
+}
+
+/// Sets the client-side rate limit, in requests per second.
+///
+/// A value of zero or less disables throttling. This is shared across all lookups,
+/// so concurrent meal fetches across many canteens stay within the limit.
+pub fn set_rate_limit(requests_per_second: f64) {
+    let mut bucket = limiter().lock().unwrap();
+    bucket.rate = requests_per_second;
+    // Hold at least one token so rates below one request per second still proceed.
+    bucket.capacity = requests_per_second.max(1.0);
+    if bucket.tokens > bucket.capacity {
+        bucket.tokens = bucket.capacity;
+    }
+}
+
+/// Waits until a request token is available, then consumes it.
+async fn acquire_token() {
+    loop {
+        let wait = {
+            let mut bucket = limiter().lock().unwrap();
+            if bucket.rate <= 0.0 {
+                return;
+            }
+            let now = SystemTime::now();
+            let elapsed = now
+                .duration_since(bucket.last_refill)
+                .unwrap_or_default()
+                .as_secs_f64();
+            bucket.last_refill = now;
+            bucket.tokens = (bucket.tokens + elapsed * bucket.rate).min(bucket.capacity);
+            if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                None
+            } else {
+                Some(Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.rate))
+            }
+        };
+        match wait {
+            None => return,
+            Some(delay) => sleep(delay).await,
+        }
+    }
+}
+
+/// Adds up to 50% random jitter to a backoff delay to avoid thundering herds.
+fn with_jitter(delay: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let fraction = f64::from(nanos % 1000) / 1000.0;
+    delay + Duration::from_secs_f64(delay.as_secs_f64() * 0.5 * fraction)
+}
+
+/// Reads the `Retry-After` header as a whole number of seconds, if present.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Sends a request, retrying transient failures with exponential backoff.
+///
+/// Connection errors and `429`/`5xx` responses are retried up to [`MAX_RETRIES`]
+/// times with a jittered, doubling backoff capped at [`MAX_BACKOFF`], honoring a
+/// `Retry-After` header when the server provides one. Every attempt first waits
+/// on the shared token-bucket limiter.
+async fn send_with_retry(request: reqwest::RequestBuilder) -> Result<reqwest::Response, Error> {
+    let mut attempt = 0u32;
+    let mut backoff = BASE_BACKOFF;
+
+    loop {
+        acquire_token().await;
+
+        let attempt_request = match request.try_clone() {
+            Some(req) => req,
+            // A non-clonable request cannot be retried, so send it as-is.
+            None => return Ok(request.send().await?),
+        };
+
+        match attempt_request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                let transient =
+                    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+                if transient && attempt < MAX_RETRIES {
+                    let delay = retry_after(&response).unwrap_or_else(|| with_jitter(backoff));
+                    attempt += 1;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    sleep(delay).await;
+                    continue;
+                }
+                return Ok(response);
+            }
+            Err(err) => {
+                if err.is_connect() && attempt < MAX_RETRIES {
+                    attempt += 1;
+                    let delay = with_jitter(backoff);
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    sleep(delay).await;
+                    continue;
+                }
+                return Err(err.into());
+            }
+        }
+    }
 }
 
 /// Fetches data from the given URL and deserializes it into the specified type.
 ///
+/// A cached response younger than `ttl` is returned without hitting the network;
+/// otherwise the fresh body is fetched, written back to the cache, and returned.
+///
 /// # Arguments
 ///
 /// * `url` - The URL to fetch data from.
+/// * `ttl` - How long a cached response for this URL remains usable.
 ///
 /// # Returns
 ///
-/// A result containing the deserialized data or a request error.
-async fn fetch_from_api<T: for<'de> Deserialize<'de>>(url: &str) -> Result<T, Error> {
-    let response = reqwest::get(url).await?.json::<T>().await?;
-    Ok(response)
+/// A result containing the deserialized data or an error.
+async fn fetch_from_api<T: for<'de> Deserialize<'de>>(
+    url: &str,
+    ttl: Duration,
+) -> Result<T, Error> {
+    if let Fetchable::Fetched(body) = read_cache(url, ttl) {
+        if let Ok(value) = serde_json::from_value::<T>(body) {
+            return Ok(value);
+        }
+    }
+
+    let client = reqwest::Client::new();
+    let response = send_with_retry(client.get(url)).await?;
+    let body = response.json::<serde_json::Value>().await?;
+    write_cache(url, &body);
+    Ok(serde_json::from_value(body)?)
+}
+
+/// Fetches every page of a paginated OpenMensa collection and concatenates them.
+///
+/// The OpenMensa API returns collections (such as the full canteen list) in pages
+/// of ~50 entries and advertises the total page count in the `X-Total-Pages`
+/// response header. This reads that header from the first response and then fetches
+/// the remaining `?page=N` pages, appending each batch to the result.
+///
+/// # Arguments
+///
+/// * `url` - The base URL of the collection to fetch.
+/// * `query` - Extra query parameters applied to every page (e.g. `near[...]`).
+/// * `limit` - An optional `limit` query parameter bounding the entries per page.
+/// * `ttl` - How long the assembled collection remains usable in the cache.
+///
+/// # Returns
+///
+/// A result containing every entry across all pages or an error.
+async fn fetch_all_pages<T: for<'de> Deserialize<'de>>(
+    url: &str,
+    query: &[(&str, String)],
+    limit: Option<u32>,
+    ttl: Duration,
+) -> Result<Vec<T>, Error> {
+    let cache_key = format!("{url}?query={query:?}&limit={limit:?}");
+    if let Fetchable::Fetched(body) = read_cache(&cache_key, ttl) {
+        if let Ok(value) = serde_json::from_value::<Vec<T>>(body) {
+            return Ok(value);
+        }
+    }
+
+    let client = reqwest::Client::new();
+    let mut pages: Vec<serde_json::Value> = Vec::new();
+    let mut page = 1u32;
+    let mut total_pages = 1u32;
+
+    loop {
+        let mut request = client
+            .get(url)
+            .query(query)
+            .query(&[("page", page.to_string())]);
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", limit.to_string())]);
+        }
+
+        let response = send_with_retry(request).await?;
+        if page == 1 {
+            total_pages = response
+                .headers()
+                .get("X-Total-Pages")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u32>().ok())
+                .unwrap_or(1);
+        }
+
+        let batch = response.json::<Vec<serde_json::Value>>().await?;
+        pages.extend(batch);
+
+        if page >= total_pages {
+            break;
+        }
+        page += 1;
+    }
+
+    let body = serde_json::Value::Array(pages);
+    write_cache(&cache_key, &body);
+    Ok(serde_json::from_value(body)?)
 }
 
 /// Gets the meals available at a specified canteen on a given date.
@@ -80,7 +530,140 @@ pub async fn get_meals(canteen: &Canteen, date: &str) -> Result<Vec<Meal>, Error
         "https://openmensa.org/api/v2/canteens/{}/days/{}/meals",
         canteen_id, date
     );
-    fetch_from_api(&menu_url).await
+    // Meals for a given day can change through the day, so keep them fresh.
+    fetch_from_api(&menu_url, Duration::from_secs(60 * 60)).await
+}
+
+/// A builder for a filtered, price-annotated meal query.
+///
+/// Start from [`MealRequest::new`], chain the optional filters, then call
+/// [`MealRequest::build`] to fetch and post-process the meals.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), mensa_cli_backend::Error> {
+/// use mensa_cli_backend::{MealRequest, PriceGroup};
+/// let meals = MealRequest::new(1, "2024-01-01")
+///     .filter_category("Vegan")
+///     .only_notes(&["vegetarian"])
+///     .price_group(PriceGroup::Students)
+///     .build()
+///     .await?;
+/// # let _ = meals;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MealRequest {
+    canteen_id: u32,
+    date: String,
+    category: Option<String>,
+    notes: Vec<String>,
+    price_group: Option<PriceGroup>,
+}
+
+impl MealRequest {
+    /// Creates a new meal request for the given canteen and date.
+    ///
+    /// # Arguments
+    ///
+    /// * `canteen_id` - The unique identifier of the canteen.
+    /// * `date` - The date to fetch meals for in YYYY-MM-DD format.
+    pub fn new(canteen_id: u32, date: &str) -> Self {
+        MealRequest {
+            canteen_id,
+            date: date.to_string(),
+            category: None,
+            notes: Vec::new(),
+            price_group: None,
+        }
+    }
+
+    /// Keeps only meals whose category equals `category`.
+    pub fn filter_category(mut self, category: &str) -> Self {
+        self.category = Some(category.to_string());
+        self
+    }
+
+    /// Keeps only meals that carry every one of the given notes.
+    pub fn only_notes(mut self, notes: &[&str]) -> Self {
+        self.notes = notes.iter().map(|note| note.to_string()).collect();
+        self
+    }
+
+    /// Selects the price group whose price is written to [`Meal::selected_price`].
+    pub fn price_group(mut self, group: PriceGroup) -> Self {
+        self.price_group = Some(group);
+        self
+    }
+
+    /// Fetches the meals and applies the configured filters and price selection.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the filtered, annotated meals or an error.
+    pub async fn build(self) -> Result<Vec<Meal>, Error> {
+        let menu_url = format!(
+            "https://openmensa.org/api/v2/canteens/{}/days/{}/meals",
+            self.canteen_id, self.date
+        );
+        let mut meals: Vec<Meal> = fetch_from_api(&menu_url, Duration::from_secs(60 * 60)).await?;
+
+        if let Some(category) = &self.category {
+            meals.retain(|meal| &meal.category == category);
+        }
+
+        if !self.notes.is_empty() {
+            meals.retain(|meal| self.notes.iter().all(|note| meal.notes.contains(note)));
+        }
+
+        if let Some(group) = self.price_group {
+            for meal in &mut meals {
+                meal.selected_price = meal.prices.for_group(group);
+            }
+        }
+
+        Ok(meals)
+    }
+}
+
+/// Gets the schedule of days for a canteen, optionally starting from a date.
+///
+/// # Arguments
+///
+/// * `canteen` - The canteen whose schedule to fetch.
+/// * `start` - An optional start date in YYYY-MM-DD format.
+///
+/// # Returns
+///
+/// A result containing a vector of days or an error.
+pub async fn get_days(canteen: &Canteen, start: Option<&str>) -> Result<Vec<Day>, Error> {
+    let mut days_url = format!(
+        "https://openmensa.org/api/v2/canteens/{}/days",
+        canteen.id
+    );
+    if let Some(start) = start {
+        days_url.push_str(&format!("?start={start}"));
+    }
+    fetch_from_api(&days_url, Duration::from_secs(60 * 60)).await
+}
+
+/// Checks whether a canteen is open on a given date.
+///
+/// # Arguments
+///
+/// * `canteen` - The canteen to check.
+/// * `date` - The date to check in YYYY-MM-DD format.
+///
+/// # Returns
+///
+/// A result containing `true` if the canteen is open on that date, otherwise
+/// `false` (including when the date is not part of the schedule), or an error.
+pub async fn is_open(canteen: &Canteen, date: &str) -> Result<bool, Error> {
+    let days = get_days(canteen, Some(date)).await?;
+    Ok(days
+        .into_iter()
+        .find(|day| day.date == date)
+        .map(|day| !day.closed)
+        .unwrap_or(false))
 }
 
 /// Gets a canteen by its unique identifier.
@@ -179,12 +762,62 @@ pub async fn get_canteens_by_locations(locations: Vec<&str>) -> Result<Vec<Cante
         .collect())
 }
 
+/// Gets canteens near the given coordinates, sorted by increasing distance.
+///
+/// This queries OpenMensa with the `near[lat]`, `near[lng]` and `near[dist]`
+/// parameters so the server returns only canteens within `dist_km` of the point.
+/// Results are re-sorted client-side via [`Canteen::distance_to`] whenever
+/// coordinates are available.
+///
+/// # Arguments
+///
+/// * `lat` - The latitude of the reference point in degrees.
+/// * `lon` - The longitude of the reference point in degrees.
+/// * `dist_km` - The search radius in kilometres.
+///
+/// # Returns
+///
+/// A result containing the nearby canteens sorted by distance or a request error.
+pub async fn get_canteens_near(lat: f64, lon: f64, dist_km: u32) -> Result<Vec<Canteen>, Error> {
+    let canteens_url = "https://openmensa.org/api/v2/canteens";
+    let query = [
+        ("near[lat]", lat.to_string()),
+        ("near[lng]", lon.to_string()),
+        ("near[dist]", dist_km.to_string()),
+    ];
+    // The `near` query hits the same paginated collection, so fetch every page.
+    let mut canteens: Vec<Canteen> =
+        fetch_all_pages(canteens_url, &query, None, Duration::from_secs(60 * 60)).await?;
+
+    canteens.sort_by(|a, b| {
+        let da = a.distance_to(lat, lon).unwrap_or(f64::INFINITY);
+        let db = b.distance_to(lat, lon).unwrap_or(f64::INFINITY);
+        da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(canteens)
+}
+
 /// Gets all available canteens.
 ///
 /// # Returns
 ///
 /// A result containing a vector of all canteens or a request error.
 pub async fn get_all_canteens() -> Result<Vec<Canteen>, Error> {
+    get_all_canteens_limited(None).await
+}
+
+/// Gets all available canteens, bounding the number of entries per page.
+///
+/// # Arguments
+///
+/// * `limit` - An optional `limit` query parameter capping the entries per page.
+///
+/// # Returns
+///
+/// A result containing a vector of all canteens or a request error.
+pub async fn get_all_canteens_limited(limit: Option<u32>) -> Result<Vec<Canteen>, Error> {
     let canteens_url = "https://openmensa.org/api/v2/canteens";
-    fetch_from_api(&canteens_url).await
+    // The canteen list rarely changes, so it can be cached for a day.
+    fetch_all_pages(canteens_url, &[], limit, Duration::from_secs(24 * 60 * 60)).await
 }